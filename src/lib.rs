@@ -19,9 +19,9 @@
 //! const KEK: u32 = 0;
 //! const WOW: u32 = 1;
 //!
-//! // Unfortunately, it is not currently possible to use one macros to parse both literals and
-//! // identifiers, so if you want to set up a macro using constants, use `numeric_enum_ident!`
-//! // macro instead.
+//! // `numeric_enum!` only accepts literal discriminants; reach for
+//! // `numeric_enum_ident!` when the discriminants are constant expressions
+//! // instead (named constants, `A | B`, `BASE + 1`, ...).
 //! numeric_enum_ident! {
 //!     #[repr(u32)] // repr must go first.
 //!     /// Some docs.
@@ -48,18 +48,494 @@
 
 #![no_std]
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __numeric_enum_rename {
+    ($enum:ident, $ren:literal) => { $ren };
+    ($enum:ident) => { stringify!($enum) };
+}
+
+/// Resolves an alternative's upper bound: a bare literal is a single-value
+/// alternative (lo == hi), a `lo..=hi` alternative keeps its own `hi`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __numeric_enum_alt_hi {
+    ($lo:literal) => { $lo };
+    ($lo:literal, $hi:literal) => { $hi };
+}
+
+/// Rejects at compile time a value (or range) claimed by more than one
+/// variant. Flattens every variant's canonical discriminant and alternatives
+/// into `(owner, lo, hi)` triples — tagging each with its own discriminant,
+/// which rustc already guarantees is unique per variant — then walks all
+/// pairs at const-eval time looking for an overlap between two different
+/// owners.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __numeric_enum_assert_no_overlap {
+    ($repr:ident; $($constant:literal $(| $altlo:literal $(..= $althi:literal)?)*),* $(,)?) => {
+        const _: () = {
+            const FLAT: &[($repr, $repr, $repr)] = &[
+                $(
+                    ($constant, $constant, $constant),
+                    $(($constant, $altlo, $crate::__numeric_enum_alt_hi!($altlo $(, $althi)?)),)*
+                )*
+            ];
+            let len = FLAT.len();
+            let mut i = 0;
+            while i < len {
+                let mut j = i + 1;
+                while j < len {
+                    let (owner_i, lo_i, hi_i) = FLAT[i];
+                    let (owner_j, lo_j, hi_j) = FLAT[j];
+                    if owner_i != owner_j && lo_i <= hi_j && lo_j <= hi_i {
+                        panic!("numeric_enum: two variants claim overlapping values");
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+    };
+}
+
+/// Rejects any of the other mode attributes (`#[table]`, `#[strings]`,
+/// `#[error = ...]`) hiding among a variant group's forwarded attributes, no
+/// matter how many unrelated attributes (`#[derive(...)]`, doc comments, ...)
+/// separate them from the one that actually selected the arm in use.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __numeric_enum_reject_mode_attrs {
+    () => {};
+    ([table] $($rest:tt)*) => {
+        compile_error!("numeric_enum: #[table] cannot be combined with another mode attribute");
+    };
+    ([strings] $($rest:tt)*) => {
+        compile_error!("numeric_enum: #[strings] cannot be combined with another mode attribute");
+    };
+    ([error = $err:ident] $($rest:tt)*) => {
+        compile_error!("numeric_enum: #[error = ...] cannot be combined with another mode attribute");
+    };
+    ($other:tt $($rest:tt)*) => {
+        $crate::__numeric_enum_reject_mode_attrs!($($rest)*);
+    };
+}
+
 /// Declares an enum with a given numeric representation defined by literals.
 ///
 /// Only explicetly enumerated enum constants are supported.
 ///
+/// A variant may list alternative accepted values in addition to its canonical
+/// discriminant, separated by `|`, e.g. `Kek = 14 | 15..=20 | 99,`. The first
+/// value is the real enum discriminant (the one `From<$name>` produces), while
+/// `TryFrom<$repr>` accepts any of the listed literals or inclusive ranges.
+/// A value (or range) claimed by more than one variant is rejected with
+/// a compile-time panic instead of being silently resolved by declaration
+/// order — rustc's own unreachable-pattern lint only fires when a whole arm is
+/// unreachable, so it stays quiet on a partial overlap like this:
+///
+/// ```compile_fail
+/// use numeric_enum_macro::numeric_enum;
+///
+/// numeric_enum! {
+///     #[repr(u8)]
+///     enum Overlapping {
+///         Zero = 0,
+///         Kek = 14 | 0..=20, // 0 is already claimed by `Zero`.
+///     }
+/// }
+/// ```
+///
+/// A single tuple-style variant may be marked with `#[catch_all]` to capture
+/// any otherwise unknown value, e.g. `#[catch_all] Unknown(u8)`. When present,
+/// `TryFrom<$repr>` becomes effectively infallible — unmatched values are
+/// wrapped in that variant instead of returning `Err` — and `From<$name>`
+/// returns the wrapped raw value for it. The catch-all variant must come last
+/// and hold a single field of the `#[repr(...)]` type.
+///
+/// Passing a `#[error = SomeError]` attribute right after `#[repr(...)]` opts in
+/// to a generated error struct instead of the bare `$repr` error. `SomeError`
+/// is spelled out explicitly rather than derived from `$name` (e.g.
+/// `LolTryFromError`): `macro_rules!` has no stable way to build a new
+/// identifier out of `$name` — that needs either nightly-only
+/// `concat_idents!` or an external proc-macro dependency such as `paste`,
+/// which this crate avoids everywhere else too (see `#[strings]`'s use of
+/// `stringify!` for the same reason). Pick whatever name avoids a collision
+/// in scope. The struct exposes the offending value through a public `value`
+/// field and a `value()` accessor, implements [`core::fmt::Display`]
+/// (`"no variant of Name matches value N"`) and [`core::fmt::Debug`], and —
+/// when the enclosing crate declares an `error_trait` feature in its own
+/// `Cargo.toml` and enables it — implements [`core::error::Error`].
+/// `TryFrom<$repr>::Error` is set to it.
+///
+/// Passing a `#[strings]` attribute right after `#[repr(...)]` additionally
+/// emits [`core::fmt::Display`], [`core::str::FromStr`] and `TryFrom<&str>`
+/// mapping each variant to and from its identifier string. A per-variant
+/// `#[rename = "..."]` attribute overrides the textual form; the string
+/// conversions return `Err(())` for unknown names, mirroring the numeric path.
+///
+/// Passing a `#[table]` attribute right after `#[repr(...)]` opts in to a
+/// table-driven `try_from`: a `const` array indexed by `value - MIN` holding
+/// `Option<$name>` is built at compile time, so the conversion is a bounds
+/// check plus an array index instead of a linear chain of comparisons. This
+/// suits enums whose discriminants form a dense, nearly-contiguous range; the
+/// table spans `MIN..=MAX`, so it is a poor fit for sparse values spread over a
+/// huge range (use the default `match` form there). Since building an array
+/// that large would blow up compile times (or overflow while sizing it), the
+/// span is capped at compile time: spanning more than 4096 values is a
+/// compile error telling you to drop `#[table]` and use the default `match`
+/// form instead:
+///
+/// ```compile_fail
+/// use numeric_enum_macro::numeric_enum;
+///
+/// numeric_enum! {
+///     #[repr(i64)]
+///     #[table]
+///     #[derive(Clone, Copy)]
+///     enum SparseAndHuge {
+///         Low = 0,
+///         High = 10_000_000, // span is ~10 million, far past the 4096 cap.
+///     }
+/// }
+/// ```
+///
+/// The enum must be `Copy`, and `Err(value)` is still returned for gaps and
+/// out-of-range values.
+///
+/// `#[error = ...]`, `#[strings]`, `#[table]` and a variant's `#[catch_all]`
+/// each select a different `try_from` strategy and cannot be mixed: `#[table]`
+/// and `#[strings]` don't support variant alternatives or `#[catch_all]`, and
+/// `#[error = ...]` doesn't support `#[catch_all]`. Combining them is a
+/// compile error that names the conflicting attributes, rather than a
+/// confusing "cannot find attribute" once the unrecognized one gets silently
+/// forwarded onto the generated enum:
+///
+/// ```compile_fail
+/// use numeric_enum_macro::numeric_enum;
+///
+/// numeric_enum! {
+///     #[repr(u8)]
+///     #[table]
+///     #[derive(Clone, Copy)]
+///     enum Combo {
+///         Zero = 0,
+///         #[catch_all]
+///         Unknown(u8),
+///     }
+/// }
+/// ```
+///
+/// This is checked regardless of how many unrelated attributes (`#[derive(...)]`,
+/// doc comments, ...) separate the two mode attributes:
+///
+/// ```compile_fail
+/// use numeric_enum_macro::numeric_enum;
+///
+/// numeric_enum! {
+///     #[repr(u8)]
+///     #[table]
+///     #[derive(Clone, Copy)]
+///     #[strings] // still rejected, even though it's not right after #[table].
+///     enum Combo2 {
+///         Zero = 0,
+///         One = 1,
+///     }
+/// }
+/// ```
+///
 /// Automatically derives `TryFrom<$repr>` and `From<$name>`.
 ///
 /// For examples look at the crate root documentation.
 #[macro_export]
 macro_rules! numeric_enum {
     (#[repr($repr:ident)]
+     #[table]
      $(#$attrs:tt)* $vis:vis enum $name:ident {
-        $($enum:ident = $constant:expr),* $(,)?
+        $($enum:ident = $constant:literal),* $(,)?
+    } ) => {
+        $crate::__numeric_enum_reject_mode_attrs!($($attrs)*);
+
+        #[repr($repr)]
+        $(#$attrs)*
+        $vis enum $name {
+            $($enum = $constant),*
+        }
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $repr;
+
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
+                const VALS: &[$repr] = &[$($constant),*];
+                const MIN: $repr = {
+                    let mut m = VALS[0];
+                    let mut i = 1;
+                    while i < VALS.len() {
+                        if VALS[i] < m {
+                            m = VALS[i];
+                        }
+                        i += 1;
+                    }
+                    m
+                };
+                const MAX: $repr = {
+                    let mut m = VALS[0];
+                    let mut i = 1;
+                    while i < VALS.len() {
+                        if VALS[i] > m {
+                            m = VALS[i];
+                        }
+                        i += 1;
+                    }
+                    m
+                };
+                const SPAN: u128 = (MAX as i128 - MIN as i128 + 1) as u128;
+                const _: () = assert!(
+                    SPAN <= 4096,
+                    "numeric_enum: #[table] span (MAX - MIN + 1) exceeds 4096; use the default match-based form for sparse/widely-spaced discriminants"
+                );
+                const N: usize = SPAN as usize;
+                const TABLE: [::core::option::Option<$name>; N] = {
+                    let mut table = [::core::option::Option::None; N];
+                    $(table[($constant as i128 - MIN as i128) as usize] = ::core::option::Option::Some($name :: $enum);)*
+                    table
+                };
+
+                if !(MIN..=MAX).contains(&value) {
+                    return ::core::result::Result::Err(value);
+                }
+                match TABLE[(value as i128 - MIN as i128) as usize] {
+                    ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                    ::core::option::Option::None => ::core::result::Result::Err(value),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                match value {
+                    $($name :: $enum => $constant,)*
+                }
+            }
+        }
+    };
+
+    // `#[table]`'s body grammar has no room for `#[catch_all]` or variant
+    // alternatives, so a body using either falls through the real arm above;
+    // catch it here with a clear diagnostic instead of letting it reach the
+    // generic arms further down.
+    (#[repr($repr:ident)]
+     #[table]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal),* $(,)?
+        #[catch_all]
+        $catch:ident ( $crepr:ident ) $(,)?
+    } ) => {
+        compile_error!("numeric_enum: #[table] does not yet support #[catch_all]; use the default match-based form with #[catch_all] instead");
+    };
+    (#[repr($repr:ident)]
+     #[table]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ),* $(,)?
+    } ) => {
+        compile_error!("numeric_enum: #[table] does not support variant alternatives (`|`); use the default match-based form instead");
+    };
+
+    (#[repr($repr:ident)]
+     #[strings]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($(#[rename = $ren:literal])? $enum:ident = $constant:literal),* $(,)?
+    } ) => {
+        $crate::__numeric_enum_reject_mode_attrs!($($attrs)*);
+
+        #[repr($repr)]
+        $(#$attrs)*
+        $vis enum $name {
+            $($enum = $constant),*
+        }
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $repr;
+
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
+                match value {
+                    $($constant => ::core::result::Result::Ok($name :: $enum),)*
+                    other => ::core::result::Result::Err(other),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                match value {
+                    $($name :: $enum => $constant,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let s = match self {
+                    $($name :: $enum => $crate::__numeric_enum_rename!($enum $(, $ren)?),)*
+                };
+                f.write_str(s)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = ();
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, ()> {
+                $(if s == $crate::__numeric_enum_rename!($enum $(, $ren)?) {
+                    return ::core::result::Result::Ok($name :: $enum);
+                })*
+                ::core::result::Result::Err(())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = ();
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, ()> {
+                <$name as ::core::str::FromStr>::from_str(s)
+            }
+        }
+    };
+
+    // Same reasoning as `#[table]` above: `#[strings]`'s body grammar has no
+    // room for `#[catch_all]` or variant alternatives either.
+    (#[repr($repr:ident)]
+     #[strings]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($(#[rename = $ren:literal])? $enum:ident = $constant:literal),* $(,)?
+        #[catch_all]
+        $catch:ident ( $crepr:ident ) $(,)?
+    } ) => {
+        compile_error!("numeric_enum: #[strings] and #[catch_all] cannot be combined");
+    };
+    (#[repr($repr:ident)]
+     #[strings]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($(#[rename = $ren:literal])? $enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ),* $(,)?
+    } ) => {
+        compile_error!("numeric_enum: #[strings] does not support variant alternatives (`|`); use the default match-based form instead");
+    };
+
+    (#[repr($repr:ident)]
+     #[error = $err:ident]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ),* $(,)?
+    } ) => {
+        $crate::__numeric_enum_reject_mode_attrs!($($attrs)*);
+
+        #[repr($repr)]
+        $(#$attrs)*
+        $vis enum $name {
+            $($enum = $constant),*
+        }
+
+        $crate::__numeric_enum_assert_no_overlap!($repr; $($constant $(| $altlo $(..= $althi)?)*),*);
+
+        /// Error returned when no variant matches the given value.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $vis struct $err {
+            /// The raw value that did not match any variant.
+            pub value: $repr,
+        }
+
+        impl $err {
+            /// Returns the raw value that did not match any variant.
+            $vis fn value(&self) -> $repr {
+                self.value
+            }
+        }
+
+        impl ::core::fmt::Display for $err {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "no variant of {} matches value {}", stringify!($name), self.value)
+            }
+        }
+
+        // Gated on a Cargo feature rather than unconditionally implemented
+        // because `core::error::Error` only stabilized recently; crates
+        // supporting older MSRVs need to opt in. Declare
+        // `error_trait = []` under `[features]` in the *using* crate's
+        // manifest and enable it to turn this impl on.
+        #[cfg(feature = "error_trait")]
+        impl ::core::error::Error for $err {}
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $err;
+
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $err> {
+                match value {
+                    $($constant $(| $altlo $(..= $althi)?)* => ::core::result::Result::Ok($name :: $enum),)*
+                    other => ::core::result::Result::Err($err { value: other }),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                match value {
+                    $($name :: $enum => $constant,)*
+                }
+            }
+        }
+    };
+
+    // `#[error = ...]`'s body grammar has no room for `#[catch_all]` either —
+    // the two are different answers to "what happens on an unknown value"
+    // and don't compose.
+    (#[repr($repr:ident)]
+     #[error = $err:ident]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ,)*
+        #[catch_all]
+        $catch:ident ( $crepr:ident ) $(,)?
+    } ) => {
+        compile_error!("numeric_enum: #[error = ...] and #[catch_all] cannot be combined");
+    };
+
+    (#[repr($repr:ident)]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ,)*
+        #[catch_all]
+        $catch:ident ( $crepr:ident ) $(,)?
+    } ) => {
+        #[repr($repr)]
+        $(#$attrs)*
+        $vis enum $name {
+            $($enum = $constant,)*
+            $catch($crepr),
+        }
+
+        $crate::__numeric_enum_assert_no_overlap!($repr; $($constant $(| $altlo $(..= $althi)?)*),*);
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $repr;
+
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
+                match value {
+                    $($constant $(| $altlo $(..= $althi)?)* => ::core::result::Result::Ok($name :: $enum),)*
+                    other => ::core::result::Result::Ok($name :: $catch(other)),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                match value {
+                    $($name :: $enum => $constant,)*
+                    $name :: $catch(other) => other,
+                }
+            }
+        }
+    };
+
+    (#[repr($repr:ident)]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:literal $(| $altlo:literal $(..= $althi:literal)?)* ),* $(,)?
     } ) => {
         #[repr($repr)]
         $(#$attrs)*
@@ -67,12 +543,14 @@ macro_rules! numeric_enum {
             $($enum = $constant),*
         }
 
+        $crate::__numeric_enum_assert_no_overlap!($repr; $($constant $(| $altlo $(..= $althi)?)*),*);
+
         impl ::core::convert::TryFrom<$repr> for $name {
             type Error = $repr;
 
             fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
                 match value {
-                    $($constant => Ok($name :: $enum),)*
+                    $($constant $(| $altlo $(..= $althi)?)* => Ok($name :: $enum),)*
                     other => Err(other),
                 }
             }
@@ -88,18 +566,30 @@ macro_rules! numeric_enum {
     }
 }
 
-/// Declares an enum with a given numeric representation defined by identifiers.
+/// Declares an enum with a given numeric representation defined by constant expressions.
 ///
 /// Only explicetly enumerated enum constants are supported.
 ///
+/// Unlike [`numeric_enum!`], the discriminants may be arbitrary constant
+/// expressions (associated consts, `A | B`, `BASE + 1`, const fn calls, ...)
+/// rather than literal patterns: `try_from` is lowered to an if-else chain
+/// (`if value == $constant`) instead of a `match`, so the values only have to
+/// be comparable with `==` rather than usable in a pattern. Comparisons run in
+/// declaration order, so the first matching constant wins.
+///
+/// Like [`numeric_enum!`], a `#[strings]` attribute right after `#[repr(...)]`
+/// additionally emits [`core::fmt::Display`], [`core::str::FromStr`] and
+/// `TryFrom<&str>`, with an optional per-variant `#[rename = "..."]`.
+///
 /// Automatically derives `TryFrom<$repr>` and `From<$name>`.
 ///
 /// For examples look at the crate root documentation.
 #[macro_export]
 macro_rules! numeric_enum_ident {
     (#[repr($repr:ident)]
+     #[strings]
      $(#$attrs:tt)* $vis:vis enum $name:ident {
-        $($enum:ident = $constant:ident),* $(,)?
+        $($(#[rename = $ren:literal])? $enum:ident = $constant:expr),* $(,)?
     } ) => {
         #[repr($repr)]
         $(#$attrs)*
@@ -111,13 +601,67 @@ macro_rules! numeric_enum_ident {
             type Error = $repr;
 
             fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
+                $(if value == $constant { return ::core::result::Result::Ok($name :: $enum); })*
+                ::core::result::Result::Err(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
                 match value {
-                    $($constant => Ok($name :: $enum),)*
-                    other => Err(other),
+                    $($name :: $enum => $constant,)*
                 }
             }
         }
 
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let s = match self {
+                    $($name :: $enum => $crate::__numeric_enum_rename!($enum $(, $ren)?),)*
+                };
+                f.write_str(s)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = ();
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, ()> {
+                $(if s == $crate::__numeric_enum_rename!($enum $(, $ren)?) {
+                    return ::core::result::Result::Ok($name :: $enum);
+                })*
+                ::core::result::Result::Err(())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = ();
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, ()> {
+                <$name as ::core::str::FromStr>::from_str(s)
+            }
+        }
+    };
+
+    (#[repr($repr:ident)]
+     $(#$attrs:tt)* $vis:vis enum $name:ident {
+        $($enum:ident = $constant:expr),* $(,)?
+    } ) => {
+        #[repr($repr)]
+        $(#$attrs)*
+        $vis enum $name {
+            $($enum = $constant),*
+        }
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $repr;
+
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $repr> {
+                $(if value == $constant { return ::core::result::Result::Ok($name :: $enum); })*
+                ::core::result::Result::Err(value)
+            }
+        }
+
         impl ::core::convert::From<$name> for $repr {
             fn from(value: $name) -> $repr {
                 match value {
@@ -151,6 +695,15 @@ mod tests {
         enum NoTrailingComa { A = 0, B = 1 }
     }
 
+    numeric_enum! {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        enum Alternatives {
+            Zero = 0,
+            Kek = 14 | 15..=20 | 99,
+        }
+    }
+
     const ZERO: u8 = 0;
     const LOL: u8 = 1;
 
@@ -162,6 +715,17 @@ mod tests {
         }
     }
 
+    const BASE: u8 = 10;
+
+    numeric_enum_ident! {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        enum ExprEnum {
+            One = BASE + 1,
+            Two = BASE + 2,
+        }
+    }
+
     #[test]
     fn it_works() {
         use core::convert::TryFrom;
@@ -171,4 +735,139 @@ mod tests {
         assert_eq!(PublicEnum::try_from(-1), Ok(PublicEnum::Lol));
         assert_eq!(PublicEnum::try_from(2), Err(2));
     }
+
+    numeric_enum! {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        enum WithCatchAll {
+            Zero = 0,
+            One = 1,
+            #[catch_all]
+            Unknown(u8),
+        }
+    }
+
+    numeric_enum! {
+        #[repr(i64)]
+        #[error = LolTryFromError]
+        #[derive(Debug, PartialEq, Eq)]
+        enum Lol {
+            Kek = 14,
+            Wow = 87,
+        }
+    }
+
+    numeric_enum! {
+        #[repr(u8)]
+        #[strings]
+        #[derive(Debug, PartialEq, Eq)]
+        enum Stringly {
+            Plain = 0,
+            #[rename = "WOW!"]
+            Wow = 1,
+        }
+    }
+
+    /// Collects `Display` output into a fixed buffer without allocating.
+    fn display_of(value: Stringly) -> [u8; 8] {
+        use core::fmt::Write;
+
+        struct Buf {
+            data: [u8; 8],
+            len: usize,
+        }
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.data[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+                self.len += s.len();
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf { data: [0; 8], len: 0 };
+        write!(buf, "{}", value).unwrap();
+        buf.data
+    }
+
+    numeric_enum! {
+        #[repr(u8)]
+        #[table]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Tabled {
+            Three = 3,
+            Four = 4,
+            Six = 6,
+        }
+    }
+
+    #[test]
+    fn table_lookup() {
+        use core::convert::TryFrom;
+
+        assert_eq!(Tabled::try_from(3), Ok(Tabled::Three));
+        assert_eq!(Tabled::try_from(4), Ok(Tabled::Four));
+        assert_eq!(Tabled::try_from(6), Ok(Tabled::Six));
+        // gap inside the span
+        assert_eq!(Tabled::try_from(5), Err(5));
+        // out of range on both ends
+        assert_eq!(Tabled::try_from(2), Err(2));
+        assert_eq!(Tabled::try_from(7), Err(7));
+        assert_eq!(3u8, Tabled::Three.into());
+    }
+
+    #[test]
+    fn string_conversions() {
+        use core::convert::TryFrom;
+        use core::str::FromStr;
+
+        assert_eq!(&display_of(Stringly::Plain)[..5], b"Plain");
+        assert_eq!(&display_of(Stringly::Wow)[..4], b"WOW!");
+        assert_eq!(Stringly::from_str("Plain"), Ok(Stringly::Plain));
+        assert_eq!(Stringly::from_str("WOW!"), Ok(Stringly::Wow));
+        assert_eq!(Stringly::from_str("Wow"), Err(()));
+        assert_eq!(Stringly::try_from("Plain"), Ok(Stringly::Plain));
+        assert_eq!(Stringly::try_from("nope"), Err(()));
+    }
+
+    #[test]
+    fn named_error() {
+        use core::convert::TryFrom;
+
+        assert_eq!(Lol::try_from(87), Ok(Lol::Wow));
+        let err = Lol::try_from(88).unwrap_err();
+        assert_eq!(err.value, 88);
+        assert_eq!(err.value(), 88);
+        assert_eq!(LolTryFromError { value: 88 }, err);
+    }
+
+    #[test]
+    fn catch_all() {
+        use core::convert::TryFrom;
+
+        assert_eq!(WithCatchAll::try_from(0), Ok(WithCatchAll::Zero));
+        assert_eq!(WithCatchAll::try_from(200), Ok(WithCatchAll::Unknown(200)));
+        assert_eq!(0u8, WithCatchAll::Zero.into());
+        assert_eq!(200u8, WithCatchAll::Unknown(200).into());
+    }
+
+    #[test]
+    fn variant_alternatives() {
+        use core::convert::TryFrom;
+
+        assert_eq!(14u8, Alternatives::Kek.into());
+        assert_eq!(Alternatives::try_from(14), Ok(Alternatives::Kek));
+        assert_eq!(Alternatives::try_from(17), Ok(Alternatives::Kek));
+        assert_eq!(Alternatives::try_from(99), Ok(Alternatives::Kek));
+        assert_eq!(Alternatives::try_from(0), Ok(Alternatives::Zero));
+        assert_eq!(Alternatives::try_from(100), Err(100));
+    }
+
+    #[test]
+    fn ident_macro_accepts_expressions() {
+        use core::convert::TryFrom;
+
+        assert_eq!(11u8, ExprEnum::One.into());
+        assert_eq!(ExprEnum::try_from(12), Ok(ExprEnum::Two));
+        assert_eq!(ExprEnum::try_from(0), Err(0));
+    }
 }